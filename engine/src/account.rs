@@ -0,0 +1,150 @@
+use std::sync::Arc;
+use std::time::Duration;
+
+use crate::config::Config;
+use crate::data::MarketEvent;
+use crate::metrics::Metrics;
+use crate::shutdown::ShutdownReason;
+use crate::storage::{OrderKind, PnlSnapshot, Store};
+use anyhow::Result;
+use tokio::sync::{broadcast, watch};
+use tracing::{info, warn};
+
+/// Placeholder starting equity until real balance sync against the
+/// exchange lands; `Executor` sizing reads this through `equity()`.
+const PLACEHOLDER_EQUITY: f64 = 10_000.0;
+
+/// How often the current equity and unrealized PnL are snapshotted to
+/// storage.
+const PNL_SNAPSHOT_INTERVAL: Duration = Duration::from_secs(60);
+
+/// A fill reported back from the exchange for a working order, consumed by
+/// `Executor` to reconcile its in-memory position against reality. `kind`
+/// distinguishes an entry confirmation from a protective stop firing, since
+/// both orders belong to the same position and a bare qty/price can't tell
+/// them apart.
+#[derive(Debug, Clone, Copy)]
+pub struct Fill {
+    pub kind: OrderKind,
+    pub qty: f64,
+    pub price: f64,
+}
+
+/// An order `Executor` has submitted, reported to `AccountManager` so it can
+/// be reconciled into a `Fill`. There is no live exchange connection yet (see
+/// `Executor`'s module docs), so every reported order is treated as an
+/// immediate full fill at its submitted price — this is what stands in for a
+/// real user-data-stream fill feed until one exists.
+#[derive(Debug, Clone, Copy)]
+pub struct PlacedOrder {
+    pub kind: OrderKind,
+    pub qty: f64,
+    pub price: f64,
+}
+
+pub struct AccountManager {
+    _config: Config,
+    metrics: Arc<Metrics>,
+    _market_data: broadcast::Receiver<MarketEvent>,
+    store: Arc<dyn Store>,
+    fill_tx: broadcast::Sender<Fill>,
+    order_tx: broadcast::Sender<PlacedOrder>,
+    // Kept alive so `order_tx.send` can't fail before `run()` starts
+    // consuming it; `Executor` may start reporting orders as soon as
+    // `order_sender()` is handed out, which happens before `run()` is spawned.
+    order_rx: broadcast::Receiver<PlacedOrder>,
+    equity_tx: watch::Sender<f64>,
+}
+
+impl AccountManager {
+    pub async fn new(
+        config: &Config,
+        metrics: Arc<Metrics>,
+        market_data: broadcast::Receiver<MarketEvent>,
+        store: Arc<dyn Store>,
+    ) -> Result<Self> {
+        let (fill_tx, _) = broadcast::channel(64);
+        let (order_tx, order_rx) = broadcast::channel(64);
+        let (equity_tx, _) = watch::channel(PLACEHOLDER_EQUITY);
+        Ok(Self {
+            _config: config.clone(),
+            metrics,
+            _market_data: market_data,
+            store,
+            fill_tx,
+            order_tx,
+            order_rx,
+            equity_tx,
+        })
+    }
+
+    /// Subscribes to exchange fills, consumed by `Executor` for reconciliation.
+    pub fn subscribe_fills(&self) -> broadcast::Receiver<Fill> {
+        self.fill_tx.subscribe()
+    }
+
+    /// Handle `Executor` reports its submitted orders through, so
+    /// `AccountManager` can turn them into `Fill`s. See `PlacedOrder`.
+    pub fn order_sender(&self) -> broadcast::Sender<PlacedOrder> {
+        self.order_tx.clone()
+    }
+
+    /// Latest known account equity, consumed by `Executor` for position sizing.
+    pub fn equity(&self) -> watch::Receiver<f64> {
+        self.equity_tx.subscribe()
+    }
+
+    pub async fn run(mut self, mut shutdown: broadcast::Receiver<ShutdownReason>) -> Result<()> {
+        let mut snapshot_interval = tokio::time::interval(PNL_SNAPSHOT_INTERVAL);
+
+        loop {
+            tokio::select! {
+                order = self.order_rx.recv() => {
+                    match order {
+                        Ok(order) => self.on_placed_order(order),
+                        Err(broadcast::error::RecvError::Lagged(skipped)) => {
+                            warn!(skipped, "AccountManager lagged behind placed-order feed");
+                        }
+                        Err(broadcast::error::RecvError::Closed) => {
+                            info!("placed-order channel closed, AccountManager exiting");
+                            return Ok(());
+                        }
+                    }
+                }
+                _ = snapshot_interval.tick() => {
+                    let snapshot = PnlSnapshot {
+                        equity: *self.equity_tx.borrow(),
+                        unrealized_pnl: self.metrics.unrealized_pnl.get(),
+                    };
+                    if let Err(e) = self.store.save_pnl_snapshot(&snapshot).await {
+                        warn!(error = %e, "failed to persist PnL snapshot");
+                    }
+                }
+                reason = shutdown.recv() => {
+                    info!(?reason, "AccountManager shutting down");
+                    let snapshot = PnlSnapshot {
+                        equity: *self.equity_tx.borrow(),
+                        unrealized_pnl: self.metrics.unrealized_pnl.get(),
+                    };
+                    if let Err(e) = self.store.save_pnl_snapshot(&snapshot).await {
+                        warn!(error = %e, "failed to persist final PnL snapshot on shutdown");
+                    }
+                    return Ok(());
+                }
+            }
+        }
+    }
+
+    /// Reconciles a submitted order into a fill. There's no exchange
+    /// connection to confirm fills against yet, so every order is treated as
+    /// filling immediately and in full at its submitted price.
+    fn on_placed_order(&self, order: PlacedOrder) {
+        let fill = Fill {
+            kind: order.kind,
+            qty: order.qty,
+            price: order.price,
+        };
+        // No subscribers yet is not an error for the account manager itself.
+        let _ = self.fill_tx.send(fill);
+    }
+}
@@ -1,4 +1,6 @@
-use anyhow::Result;
+use anyhow::{anyhow, Result};
+use figment::providers::{Env, Format, Toml};
+use figment::Figment;
 use serde::Deserialize;
 
 #[derive(Debug, Clone, Deserialize)]
@@ -8,6 +10,7 @@ pub struct Config {
     pub symbols: SymbolsConfig,
     pub thresholds: ThresholdsConfig,
     pub risk: RiskConfig,
+    pub storage: StorageConfig,
 }
 
 #[derive(Debug, Clone, Deserialize)]
@@ -40,29 +43,67 @@ pub struct RiskConfig {
     pub stop_loss: f64,
 }
 
+#[derive(Debug, Clone, Deserialize)]
+#[allow(dead_code)]
+pub struct StorageConfig {
+    pub database_url: String,
+    pub pool_size: u32,
+}
+
 impl Config {
+    /// Loads configuration from a layered provider stack: `config.toml`, then
+    /// an optional `config.<SOLRUST_ENV>.toml` override, then environment
+    /// variables prefixed `SOLRUST_` (double underscore nests, e.g.
+    /// `SOLRUST_EXCHANGE__BINANCE_KEY` -> `exchange.binance_key`). Secrets
+    /// should only ever be supplied via the env layer, never the TOML files.
     pub async fn load() -> Result<Self> {
-        // TODO: Implement config loading from TOML and .env
-        // This is a stub implementation for now
-        Ok(Config {
-            exchange: ExchangeConfig {
-                binance_key: "test".to_string(),
-                binance_sec: "test".to_string(),
-            },
-            symbols: SymbolsConfig {
-                spot: "SOLUSDT".to_string(),
-                hedge: "SOLUSD_PERP".to_string(),
-            },
-            thresholds: ThresholdsConfig {
-                meme_drop_pct: 0.30,
-                rsi_max: 45.0,
-                support_low: 160.0,
-                support_high: 162.0,
-            },
-            risk: RiskConfig {
-                max_trade_risk: 0.05,
-                stop_loss: 155.0,
-            },
-        })
+        let env = std::env::var("SOLRUST_ENV").unwrap_or_else(|_| "development".to_string());
+        let env_file = format!("config.{env}.toml");
+
+        let config: Config = Figment::new()
+            .merge(Toml::file("config.toml"))
+            .merge(Toml::file(&env_file))
+            .merge(Env::prefixed("SOLRUST_").split("__"))
+            .extract()
+            .map_err(|e| anyhow!("failed to load configuration: {e}"))?;
+
+        config.validate()?;
+        Ok(config)
+    }
+
+    /// Checks the cross-field invariants that `Deserialize` alone can't
+    /// express, so a misconfigured deployment fails fast with a field name
+    /// instead of producing nonsensical trading behavior at runtime.
+    fn validate(&self) -> Result<()> {
+        let t = &self.thresholds;
+        let r = &self.risk;
+
+        if !(t.support_low < t.support_high) {
+            return Err(anyhow!(
+                "thresholds.support_low ({}) must be less than thresholds.support_high ({})",
+                t.support_low,
+                t.support_high
+            ));
+        }
+        if !(r.max_trade_risk > 0.0 && r.max_trade_risk <= 1.0) {
+            return Err(anyhow!(
+                "risk.max_trade_risk ({}) must be in (0.0, 1.0]",
+                r.max_trade_risk
+            ));
+        }
+        if !(r.stop_loss > 0.0) {
+            return Err(anyhow!("risk.stop_loss ({}) must be greater than 0", r.stop_loss));
+        }
+        if !(t.meme_drop_pct > 0.0 && t.meme_drop_pct < 1.0) {
+            return Err(anyhow!(
+                "thresholds.meme_drop_pct ({}) must be in (0.0, 1.0)",
+                t.meme_drop_pct
+            ));
+        }
+        if self.storage.pool_size == 0 {
+            return Err(anyhow!("storage.pool_size must be greater than 0"));
+        }
+
+        Ok(())
     }
 }
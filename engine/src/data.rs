@@ -1,21 +1,249 @@
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
 use crate::config::Config;
-use anyhow::Result;
+use crate::metrics::Metrics;
+use crate::shutdown::ShutdownReason;
+use anyhow::{anyhow, Result};
+use futures_util::StreamExt;
+use serde_json::Value;
+use tokio::sync::broadcast;
+use tokio_tungstenite::connect_async;
+use tokio_tungstenite::tungstenite::Message;
+use tracing::{info, warn};
+
+const INITIAL_BACKOFF: Duration = Duration::from_secs(1);
+const MAX_BACKOFF: Duration = Duration::from_secs(30);
+/// A connection that stays up at least this long is considered stable,
+/// resetting backoff to `INITIAL_BACKOFF` on its next drop instead of
+/// continuing to grow from wherever backoff last left off.
+const STABLE_CONNECTION_THRESHOLD: Duration = Duration::from_secs(60);
+
+/// A normalized market data event, decoupled from Binance's raw wire format
+/// so `SignalEngine` and `AccountManager` don't need to know which stream
+/// (spot vs. perp) or frame type produced it.
+#[derive(Debug, Clone)]
+pub enum MarketEvent {
+    Trade {
+        symbol: String,
+        price: f64,
+        quantity: f64,
+        timestamp_ms: i64,
+    },
+    BookTicker {
+        symbol: String,
+        bid: f64,
+        ask: f64,
+    },
+    Kline {
+        symbol: String,
+        open: f64,
+        high: f64,
+        low: f64,
+        close: f64,
+        is_closed: bool,
+        timestamp_ms: i64,
+    },
+}
+
+impl MarketEvent {
+    pub fn symbol(&self) -> &str {
+        match self {
+            MarketEvent::Trade { symbol, .. } => symbol,
+            MarketEvent::BookTicker { symbol, .. } => symbol,
+            MarketEvent::Kline { symbol, .. } => symbol,
+        }
+    }
+}
+
+/// Which Binance market a stream belongs to, since spot and perp futures use
+/// different hosts.
+#[derive(Debug, Clone, Copy)]
+enum Market {
+    Spot,
+    Perp,
+}
 
+impl Market {
+    fn stream_url(self, symbol: &str) -> String {
+        let lower = symbol.to_lowercase();
+        let streams = format!("{lower}@trade/{lower}@bookTicker/{lower}@kline_1m");
+        match self {
+            Market::Spot => format!("wss://stream.binance.com:9443/stream?streams={streams}"),
+            Market::Perp => format!("wss://fstream.binance.com/stream?streams={streams}"),
+        }
+    }
+}
+
+/// Multiplexes live Binance market data for `symbols.spot` and
+/// `symbols.hedge` into a single broadcast channel that downstream
+/// components subscribe to.
 pub struct DataMux {
-    _config: Config,
+    config: Config,
+    metrics: Arc<Metrics>,
+    tx: broadcast::Sender<MarketEvent>,
 }
 
 impl DataMux {
-    pub async fn new(config: &Config) -> Result<Self> {
+    pub async fn new(config: &Config, metrics: Arc<Metrics>) -> Result<Self> {
+        let (tx, _) = broadcast::channel(1024);
         Ok(Self {
-            _config: config.clone(),
+            config: config.clone(),
+            metrics,
+            tx,
         })
     }
 
-    pub async fn run(self) -> Result<()> {
-        // TODO: Implement data multiplexer
-        // This is a stub implementation for now
-        tokio::time::sleep(tokio::time::Duration::from_secs(3600)).await;
+    /// Subscribes to the normalized market event stream. Intended to be
+    /// called once per downstream component before `run()` is spawned.
+    pub fn subscribe(&self) -> broadcast::Receiver<MarketEvent> {
+        self.tx.subscribe()
+    }
+
+    pub async fn run(self, mut shutdown: broadcast::Receiver<ShutdownReason>) -> Result<()> {
+        let spot = tokio::spawn(feed_loop(
+            Market::Spot,
+            self.config.symbols.spot.clone(),
+            self.tx.clone(),
+            self.metrics.clone(),
+            shutdown.resubscribe(),
+        ));
+        let perp = tokio::spawn(feed_loop(
+            Market::Perp,
+            self.config.symbols.hedge.clone(),
+            self.tx.clone(),
+            self.metrics.clone(),
+            shutdown.resubscribe(),
+        ));
+
+        let reason = shutdown.recv().await;
+        info!(?reason, "DataMux shutting down");
+        let _ = tokio::join!(spot, perp);
         Ok(())
     }
 }
+
+/// Keeps a single symbol's feed alive, reconnecting with exponential backoff
+/// and resubscribing to the stream whenever the socket drops.
+async fn feed_loop(
+    market: Market,
+    symbol: String,
+    tx: broadcast::Sender<MarketEvent>,
+    metrics: Arc<Metrics>,
+    mut shutdown: broadcast::Receiver<ShutdownReason>,
+) {
+    let mut backoff = INITIAL_BACKOFF;
+
+    loop {
+        let connected_at = Instant::now();
+        tokio::select! {
+            result = connect_and_stream(market, &symbol, &tx, &metrics) => {
+                if let Err(e) = result {
+                    warn!(symbol = %symbol, error = %e, ?backoff, "market data feed disconnected, reconnecting");
+                }
+                // connect_and_stream never returns Ok(()) in practice (every
+                // exit is an error), so resetting backoff has to be based on
+                // how long the connection actually stayed up rather than on
+                // an Ok/Err split.
+                if connected_at.elapsed() >= STABLE_CONNECTION_THRESHOLD {
+                    backoff = INITIAL_BACKOFF;
+                }
+            }
+            _ = shutdown.recv() => return,
+        }
+
+        tokio::select! {
+            _ = tokio::time::sleep(backoff) => {}
+            _ = shutdown.recv() => return,
+        }
+        backoff = (backoff * 2).min(MAX_BACKOFF);
+    }
+}
+
+async fn connect_and_stream(
+    market: Market,
+    symbol: &str,
+    tx: &broadcast::Sender<MarketEvent>,
+    metrics: &Arc<Metrics>,
+) -> Result<()> {
+    let url = market.stream_url(symbol);
+    let (ws_stream, _) = connect_async(&url).await?;
+    let (_, mut read) = ws_stream.split();
+
+    while let Some(message) = read.next().await {
+        match message? {
+            Message::Text(text) => {
+                if let Some(event) = parse_event(symbol, &text)? {
+                    metrics.ticks_received_total.inc();
+                    metrics
+                        .market_data_last_tick_timestamp_seconds
+                        .set(now_unix_seconds());
+                    // No subscribers yet is not an error for the feed itself.
+                    let _ = tx.send(event);
+                }
+            }
+            Message::Close(frame) => {
+                return Err(anyhow!("stream for {symbol} closed by server: {frame:?}"));
+            }
+            _ => {}
+        }
+    }
+
+    Err(anyhow!("stream for {symbol} ended unexpectedly"))
+}
+
+/// Parses a raw Binance combined-stream frame into a `MarketEvent`, or
+/// `None` for event types we don't care about.
+fn parse_event(symbol: &str, text: &str) -> Result<Option<MarketEvent>> {
+    let envelope: Value = serde_json::from_str(text)?;
+    let data = envelope.get("data").unwrap_or(&envelope);
+    let event_type = data.get("e").and_then(Value::as_str).unwrap_or_default();
+
+    let event = match event_type {
+        "trade" => Some(MarketEvent::Trade {
+            symbol: symbol.to_string(),
+            price: parse_field(data, "p")?,
+            quantity: parse_field(data, "q")?,
+            timestamp_ms: data.get("T").and_then(Value::as_i64).unwrap_or_default(),
+        }),
+        "bookTicker" => Some(MarketEvent::BookTicker {
+            symbol: symbol.to_string(),
+            bid: parse_field(data, "b")?,
+            ask: parse_field(data, "a")?,
+        }),
+        "kline" => {
+            let k = data
+                .get("k")
+                .ok_or_else(|| anyhow!("kline frame for {symbol} missing 'k'"))?;
+            Some(MarketEvent::Kline {
+                symbol: symbol.to_string(),
+                open: parse_field(k, "o")?,
+                high: parse_field(k, "h")?,
+                low: parse_field(k, "l")?,
+                close: parse_field(k, "c")?,
+                is_closed: k.get("x").and_then(Value::as_bool).unwrap_or(false),
+                timestamp_ms: k.get("t").and_then(Value::as_i64).unwrap_or_default(),
+            })
+        }
+        _ => None,
+    };
+
+    Ok(event)
+}
+
+/// Binance encodes decimal fields as JSON strings; parse defensively.
+fn parse_field(value: &Value, key: &str) -> Result<f64> {
+    value
+        .get(key)
+        .and_then(Value::as_str)
+        .ok_or_else(|| anyhow!("missing or non-string field '{key}'"))?
+        .parse()
+        .map_err(|e| anyhow!("failed to parse field '{key}': {e}"))
+}
+
+fn now_unix_seconds() -> f64 {
+    std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_secs_f64()
+}
@@ -1,21 +1,400 @@
+use std::sync::Arc;
+use std::time::Instant;
+
+use crate::account::{Fill, PlacedOrder};
 use crate::config::Config;
+use crate::data::MarketEvent;
+use crate::metrics::Metrics;
+use crate::shutdown::ShutdownReason;
+use crate::signal::{Side, Signal};
+use crate::storage::{FillRecord, OpenPosition, OrderKind, OrderRecord, Store};
 use anyhow::Result;
+use tokio::sync::{broadcast, watch};
+use tracing::{debug, info, warn};
+
+/// The single open position `Executor` is willing to carry at a time, plus
+/// enough context to reconcile it against a reported fill.
+#[allow(dead_code)]
+struct Position {
+    side: Side,
+    qty: f64,
+    entry_price: f64,
+    stop_price: f64,
+}
+
+impl Position {
+    /// Position size signed by side, for the `position_size` gauge.
+    fn signed_qty(&self) -> f64 {
+        match self.side {
+            Side::Long => self.qty,
+            Side::Short => -self.qty,
+        }
+    }
+
+    fn unrealized_pnl(&self, last_price: f64) -> f64 {
+        match self.side {
+            Side::Long => (last_price - self.entry_price) * self.qty,
+            Side::Short => (self.entry_price - last_price) * self.qty,
+        }
+    }
+}
 
+/// Sizes and places entries/stops from `Signal`s and reconciles them against
+/// reported `Fill`s. There is no live Binance REST connection yet (order
+/// placement only logs and records to storage), so this currently runs as a
+/// paper executor: `AccountManager` treats every order it reports as an
+/// immediate full fill rather than waiting on a real exchange confirmation.
+/// Wiring a signed REST client in is future work; until then this traces the
+/// shape real execution will take without risking real funds.
 pub struct Executor {
-    _config: Config,
+    config: Config,
+    metrics: Arc<Metrics>,
+    signals: broadcast::Receiver<Signal>,
+    market_data: broadcast::Receiver<MarketEvent>,
+    fills: broadcast::Receiver<Fill>,
+    orders: broadcast::Sender<PlacedOrder>,
+    equity: watch::Receiver<f64>,
+    store: Arc<dyn Store>,
+    last_price: Option<f64>,
+    position: Option<Position>,
+    /// Set once a protective stop has been reported to `AccountManager`, so a
+    /// still-live position doesn't re-trigger the stop on every subsequent
+    /// tick while its fill is in flight.
+    stop_pending: bool,
 }
 
 impl Executor {
-    pub async fn new(config: &Config) -> Result<Self> {
+    #[allow(clippy::too_many_arguments)]
+    pub async fn new(
+        config: &Config,
+        metrics: Arc<Metrics>,
+        signals: broadcast::Receiver<Signal>,
+        market_data: broadcast::Receiver<MarketEvent>,
+        fills: broadcast::Receiver<Fill>,
+        orders: broadcast::Sender<PlacedOrder>,
+        equity: watch::Receiver<f64>,
+        store: Arc<dyn Store>,
+        resumed_position: Option<OpenPosition>,
+    ) -> Result<Self> {
+        let position = resumed_position.and_then(|p| {
+            let side = Side::parse(&p.side)?;
+            Some(Position {
+                side,
+                qty: p.qty,
+                entry_price: p.entry_price,
+                stop_price: p.stop_price,
+            })
+        });
+        if let Some(position) = &position {
+            info!("resumed open position from storage");
+            metrics.position_size.set(position.signed_qty());
+        }
+
         Ok(Self {
-            _config: config.clone(),
+            config: config.clone(),
+            metrics,
+            signals,
+            market_data,
+            fills,
+            orders,
+            equity,
+            store,
+            last_price: None,
+            position,
+            stop_pending: false,
         })
     }
 
-    pub async fn run(self) -> Result<()> {
-        // TODO: Implement order executor
-        // This is a stub implementation for now
-        tokio::time::sleep(tokio::time::Duration::from_secs(3600)).await;
+    pub async fn run(mut self, mut shutdown: broadcast::Receiver<ShutdownReason>) -> Result<()> {
+        loop {
+            tokio::select! {
+                event = self.market_data.recv() => {
+                    match event {
+                        Ok(event) => self.on_market_event(event).await?,
+                        Err(broadcast::error::RecvError::Lagged(skipped)) => {
+                            debug!(skipped, "Executor lagged behind market data feed");
+                        }
+                        Err(broadcast::error::RecvError::Closed) => {
+                            info!("market data channel closed, Executor exiting");
+                            return Ok(());
+                        }
+                    }
+                }
+                signal = self.signals.recv() => {
+                    match signal {
+                        Ok(signal) => self.on_signal(signal).await?,
+                        Err(broadcast::error::RecvError::Lagged(skipped)) => {
+                            debug!(skipped, "Executor lagged behind signal feed");
+                        }
+                        Err(broadcast::error::RecvError::Closed) => {
+                            info!("signal channel closed, Executor exiting");
+                            return Ok(());
+                        }
+                    }
+                }
+                fill = self.fills.recv() => {
+                    match fill {
+                        Ok(fill) => self.on_fill(fill).await?,
+                        Err(broadcast::error::RecvError::Lagged(skipped)) => {
+                            debug!(skipped, "Executor lagged behind fill feed");
+                        }
+                        Err(broadcast::error::RecvError::Closed) => {
+                            info!("fill channel closed, Executor exiting");
+                            return Ok(());
+                        }
+                    }
+                }
+                reason = shutdown.recv() => {
+                    info!(?reason, "Executor shutting down");
+                    self.flatten_on_shutdown().await?;
+                    return Ok(());
+                }
+            }
+        }
+    }
+
+    async fn on_market_event(&mut self, event: MarketEvent) -> Result<()> {
+        if event.symbol() != self.config.symbols.spot {
+            return Ok(());
+        }
+        match event {
+            MarketEvent::Trade { price, .. } => self.last_price = Some(price),
+            MarketEvent::Kline { close, .. } => self.last_price = Some(close),
+            MarketEvent::BookTicker { .. } => return Ok(()),
+        }
+
+        let (Some(position), Some(last_price)) = (&self.position, self.last_price) else {
+            return Ok(());
+        };
+        self.metrics.unrealized_pnl.set(position.unrealized_pnl(last_price));
+
+        let stop_triggered = match position.side {
+            Side::Long => last_price <= position.stop_price,
+            Side::Short => last_price >= position.stop_price,
+        };
+        if stop_triggered && !self.stop_pending {
+            // There's no live exchange to trigger the resting stop order, so
+            // the paper executor fires it itself once price crosses the stop;
+            // `stop_pending` stops it from firing again on every subsequent
+            // tick before the fill round-trips back through `on_fill`.
+            info!(stop_price = position.stop_price, last_price, "protective stop triggered");
+            self.stop_pending = true;
+            let _ = self.orders.send(PlacedOrder {
+                kind: OrderKind::Stop,
+                qty: position.qty,
+                price: last_price,
+            });
+        }
+
+        Ok(())
+    }
+
+    async fn on_signal(&mut self, signal: Signal) -> Result<()> {
+        let received_at = Instant::now();
+
+        if self.position.is_some() {
+            debug!("signal received while a position is already live, ignoring");
+            return Ok(());
+        }
+
+        let Some(entry_price) = self.last_price else {
+            warn!("signal received before any market price was observed, ignoring");
+            return Ok(());
+        };
+
+        let stop_price = self.config.risk.stop_loss;
+        if entry_price <= stop_price {
+            warn!(entry_price, stop_price, "entry is not above the configured stop, refusing to size");
+            return Ok(());
+        }
+
+        let equity = *self.equity.borrow();
+        let qty = position_qty(equity, self.config.risk.max_trade_risk, entry_price, stop_price);
+
+        info!(
+            side = ?signal.side,
+            strength = signal.strength,
+            qty,
+            entry_price,
+            stop_price,
+            "opening position from signal"
+        );
+
+        self.place_entry_order(signal.side, qty, entry_price).await?;
+        self.place_stop_order(signal.side, qty, stop_price).await?;
+
+        self.store
+            .save_position(&OpenPosition {
+                symbol: self.config.symbols.spot.clone(),
+                side: signal.side.as_str().to_string(),
+                qty,
+                entry_price,
+                stop_price,
+            })
+            .await?;
+
+        self.position = Some(Position {
+            side: signal.side,
+            qty,
+            entry_price,
+            stop_price,
+        });
+        self.stop_pending = false;
+        self.metrics
+            .position_size
+            .set(self.position.as_ref().expect("just set above").signed_qty());
+
+        self.metrics
+            .signal_to_execution_latency
+            .observe(received_at.elapsed().as_secs_f64());
+
+        Ok(())
+    }
+
+    async fn on_fill(&mut self, fill: Fill) -> Result<()> {
+        if self.position.is_none() {
+            debug!(?fill, "fill received with no open position to reconcile");
+            return Ok(());
+        }
+
+        self.store
+            .save_fill(&FillRecord {
+                symbol: self.config.symbols.spot.clone(),
+                qty: fill.qty,
+                price: fill.price,
+            })
+            .await?;
+
+        // Entry and stop fills belong to the same position, so only the
+        // stop firing actually closes it; an entry fill just confirms the
+        // position is live and updates its recorded entry price.
+        match fill.kind {
+            OrderKind::Entry => {
+                if let Some(position) = &mut self.position {
+                    position.entry_price = fill.price;
+                }
+                debug!(fill_qty = fill.qty, fill_price = fill.price, "entry order filled");
+            }
+            OrderKind::Stop => {
+                info!(fill_qty = fill.qty, fill_price = fill.price, "stop-loss filled, closing position");
+                self.store.close_position(&self.config.symbols.spot).await?;
+                self.position = None;
+                self.stop_pending = false;
+                self.metrics.position_size.set(0.0);
+                self.metrics.unrealized_pnl.set(0.0);
+            }
+        }
+
         Ok(())
     }
+
+    /// Flattens any open position on shutdown so a SIGTERM/SIGHUP doesn't
+    /// leave an orphaned position with no bot left to manage its stop.
+    async fn flatten_on_shutdown(&mut self) -> Result<()> {
+        let Some(position) = self.position.take() else {
+            return Ok(());
+        };
+
+        let exit_price = self.last_price.unwrap_or(position.entry_price);
+        info!(
+            side = ?position.side,
+            qty = position.qty,
+            exit_price,
+            "flattening open position for shutdown"
+        );
+
+        // No live exchange connection yet (see Executor's module docs), so
+        // "flattening" means marking the position closed in storage; there is
+        // no real exchange-side order to cancel.
+        self.store
+            .save_order(&OrderRecord {
+                symbol: self.config.symbols.spot.clone(),
+                side: position.side.as_str().to_string(),
+                kind: OrderKind::Stop,
+                qty: position.qty,
+                price: exit_price,
+            })
+            .await?;
+        self.store.close_position(&self.config.symbols.spot).await?;
+
+        self.metrics.position_size.set(0.0);
+        self.metrics.unrealized_pnl.set(0.0);
+
+        Ok(())
+    }
+
+    async fn place_entry_order(&self, side: Side, qty: f64, price: f64) -> Result<()> {
+        // No live Binance REST connection yet (see Executor's module docs),
+        // so this records the order and reports it to AccountManager as an
+        // immediate paper fill rather than submitting it to the exchange.
+        info!(?side, qty, price, "submitting entry order");
+        self.store
+            .save_order(&OrderRecord {
+                symbol: self.config.symbols.spot.clone(),
+                side: side.as_str().to_string(),
+                kind: OrderKind::Entry,
+                qty,
+                price,
+            })
+            .await?;
+        self.metrics.orders_placed_total.inc();
+        let _ = self.orders.send(PlacedOrder { kind: OrderKind::Entry, qty, price });
+        Ok(())
+    }
+
+    async fn place_stop_order(&self, side: Side, qty: f64, stop_price: f64) -> Result<()> {
+        // No live Binance REST connection yet (see Executor's module docs).
+        // This records the resting stop order; unlike the entry, it isn't
+        // reported as an immediate fill — `on_market_event` reports it to
+        // AccountManager once a tick actually crosses `stop_price`.
+        info!(?side, qty, stop_price, "submitting protective stop-loss order");
+        self.store
+            .save_order(&OrderRecord {
+                symbol: self.config.symbols.spot.clone(),
+                side: side.as_str().to_string(),
+                kind: OrderKind::Stop,
+                qty,
+                price: stop_price,
+            })
+            .await?;
+        self.metrics.orders_placed_total.inc();
+        Ok(())
+    }
+}
+
+/// Sizes a position so that a full stop-out at `stop_price` loses no more
+/// than `max_trade_risk` of `equity`. Callers are expected to have already
+/// checked `entry_price > stop_price`.
+fn position_qty(equity: f64, max_trade_risk: f64, entry_price: f64, stop_price: f64) -> f64 {
+    (equity * max_trade_risk) / (entry_price - stop_price)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn position_qty_caps_loss_at_max_trade_risk() {
+        let equity = 10_000.0;
+        let max_trade_risk = 0.01;
+        let entry_price = 100.0;
+        let stop_price = 95.0;
+
+        let qty = position_qty(equity, max_trade_risk, entry_price, stop_price);
+        let loss_if_stopped_out = (entry_price - stop_price) * qty;
+
+        assert!((loss_if_stopped_out - equity * max_trade_risk).abs() < 1e-9);
+    }
+
+    #[test]
+    fn position_qty_grows_as_stop_narrows() {
+        let equity = 10_000.0;
+        let max_trade_risk = 0.01;
+        let entry_price = 100.0;
+
+        let wide_stop_qty = position_qty(equity, max_trade_risk, entry_price, 90.0);
+        let narrow_stop_qty = position_qty(equity, max_trade_risk, entry_price, 99.0);
+
+        assert!(narrow_stop_qty > wide_stop_qty);
+    }
 }
@@ -1,3 +1,6 @@
+use std::sync::Arc;
+use std::time::Duration;
+
 use anyhow::Result;
 use tracing::{info, warn};
 
@@ -5,16 +8,25 @@ mod account;
 mod config;
 mod data;
 mod execution;
+mod metrics;
+mod shutdown;
 mod signal;
+mod storage;
 mod telemetry;
 
 use account::AccountManager;
 use config::Config;
 use data::DataMux;
 use execution::Executor;
+use metrics::Metrics;
 use signal::SignalEngine;
+use storage::{PostgresStore, Store};
 use telemetry::TelemetryServer;
 
+/// Bound on how long a component's `run()` loop gets to flush state and
+/// cancel in-flight orders after a shutdown signal before it's abandoned.
+const SHUTDOWN_TIMEOUT: Duration = Duration::from_secs(10);
+
 #[tokio::main]
 async fn main() -> Result<()> {
     // Initialize tracing
@@ -26,43 +38,97 @@ async fn main() -> Result<()> {
     let config = Config::load().await?;
     info!("Configuration loaded successfully");
 
+    // Shared Prometheus registry, threaded into every component below
+    let metrics = Metrics::new()?;
+
+    // Persistent trade/fill store; reload the last open position so the bot
+    // resumes reconciliation after a crash instead of starting blind.
+    let store: Arc<dyn Store> = Arc::new(PostgresStore::connect(&config, metrics.clone()).await?);
+    let resumed_position = store.load_open_position().await?;
+    if resumed_position.is_some() {
+        info!("resumed open position from storage");
+    }
+
+    // Broadcasts a shutdown signal to every component on SIGINT/SIGTERM/SIGHUP
+    let shutdown_tx = shutdown::install()?;
+
     // Start telemetry server
-    let telemetry = TelemetryServer::new(&config).await?;
+    let telemetry = TelemetryServer::new(&config, metrics.clone()).await?;
     let telemetry_handle = tokio::spawn(async move {
         if let Err(e) = telemetry.run().await {
             warn!("Telemetry server error: {}", e);
         }
     });
 
-    // Initialize core components
-    let data_mux = DataMux::new(&config).await?;
-    let signal_engine = SignalEngine::new(&config).await?;
-    let executor = Executor::new(&config).await?;
-    let account_manager = AccountManager::new(&config).await?;
+    // Initialize core components. DataMux is created first so every other
+    // component can subscribe to its market data broadcast; AccountManager
+    // and SignalEngine before Executor so it can subscribe to fills, equity
+    // and trade signals.
+    let data_mux = DataMux::new(&config, metrics.clone()).await?;
+    let account_manager = AccountManager::new(
+        &config,
+        metrics.clone(),
+        data_mux.subscribe(),
+        store.clone(),
+    )
+    .await?;
+    let signal_engine =
+        SignalEngine::new(&config, metrics.clone(), data_mux.subscribe()).await?;
+    let executor = Executor::new(
+        &config,
+        metrics.clone(),
+        signal_engine.subscribe(),
+        data_mux.subscribe(),
+        account_manager.subscribe_fills(),
+        account_manager.order_sender(),
+        account_manager.equity(),
+        store.clone(),
+        resumed_position,
+    )
+    .await?;
 
     info!("All components initialized, starting main loop");
 
-    // Main event loop using tokio::select!
-    tokio::select! {
-        result = data_mux.run() => {
-            warn!("DataMux terminated: {:?}", result);
-        }
-        result = signal_engine.run() => {
-            warn!("SignalEngine terminated: {:?}", result);
+    // Each component races its own work against the shutdown broadcast, so
+    // run() returns cleanly (flushing state, cancelling orders) rather than
+    // being aborted mid-operation.
+    let data_mux_handle = tokio::spawn(data_mux.run(shutdown_tx.subscribe()));
+    let signal_engine_handle = tokio::spawn(signal_engine.run(shutdown_tx.subscribe()));
+    let executor_handle = tokio::spawn(executor.run(shutdown_tx.subscribe()));
+    let account_manager_handle = tokio::spawn(account_manager.run(shutdown_tx.subscribe()));
+
+    // Wait for a shutdown signal (installed above) before unwinding
+    let mut shutdown_rx = shutdown_tx.subscribe();
+    let _ = shutdown_rx.recv().await;
+
+    info!("Shutting down SolVolumeBot");
+
+    let joined = tokio::time::timeout(SHUTDOWN_TIMEOUT, async {
+        let (data_mux_result, signal_engine_result, executor_result, account_manager_result) = tokio::join!(
+            data_mux_handle,
+            signal_engine_handle,
+            executor_handle,
+            account_manager_handle,
+        );
+        if let Err(e) = data_mux_result {
+            warn!("DataMux task panicked: {}", e);
         }
-        result = executor.run() => {
-            warn!("Executor terminated: {:?}", result);
+        if let Err(e) = signal_engine_result {
+            warn!("SignalEngine task panicked: {}", e);
         }
-        result = account_manager.run() => {
-            warn!("AccountManager terminated: {:?}", result);
+        if let Err(e) = executor_result {
+            warn!("Executor task panicked: {}", e);
         }
-        _ = tokio::signal::ctrl_c() => {
-            info!("Received shutdown signal");
+        if let Err(e) = account_manager_result {
+            warn!("AccountManager task panicked: {}", e);
         }
+    })
+    .await;
+
+    if joined.is_err() {
+        warn!("components did not shut down within {SHUTDOWN_TIMEOUT:?}, abandoning them");
     }
 
-    // Graceful shutdown
-    info!("Shutting down SolVolumeBot");
     telemetry_handle.abort();
 
     Ok(())
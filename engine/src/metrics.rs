@@ -0,0 +1,90 @@
+use std::sync::Arc;
+
+use anyhow::Result;
+use prometheus::{Encoder, Gauge, Histogram, HistogramOpts, IntCounter, Opts, Registry, TextEncoder};
+
+/// Central Prometheus registry shared by every subsystem. Constructed once in
+/// `main` and cloned (via `Arc`) into each component so telemetry is wired
+/// through the same handles that actually observe bot behavior, rather than
+/// each component reinventing its own counters.
+pub struct Metrics {
+    registry: Registry,
+    pub ticks_received_total: IntCounter,
+    pub orders_placed_total: IntCounter,
+    pub position_size: Gauge,
+    pub unrealized_pnl: Gauge,
+    pub signal_to_execution_latency: Histogram,
+    pub market_data_last_tick_timestamp_seconds: Gauge,
+    pub db_pool_connections: Gauge,
+    pub db_pool_idle_connections: Gauge,
+}
+
+impl Metrics {
+    pub fn new() -> Result<Arc<Self>> {
+        let registry = Registry::new();
+
+        let ticks_received_total = IntCounter::with_opts(Opts::new(
+            "sol_volume_bot_ticks_received_total",
+            "Total number of market data ticks received from the exchange",
+        ))?;
+        let orders_placed_total = IntCounter::with_opts(Opts::new(
+            "sol_volume_bot_orders_placed_total",
+            "Total number of orders submitted to the exchange",
+        ))?;
+        let position_size = Gauge::with_opts(Opts::new(
+            "sol_volume_bot_position_size",
+            "Current open position size, signed by side",
+        ))?;
+        let unrealized_pnl = Gauge::with_opts(Opts::new(
+            "sol_volume_bot_unrealized_pnl",
+            "Unrealized profit and loss of the current open position",
+        ))?;
+        let signal_to_execution_latency = Histogram::with_opts(HistogramOpts::new(
+            "sol_volume_bot_signal_to_execution_latency_seconds",
+            "Latency from the executor receiving a signal to order placement",
+        ))?;
+        let market_data_last_tick_timestamp_seconds = Gauge::with_opts(Opts::new(
+            "sol_volume_bot_market_data_last_tick_timestamp_seconds",
+            "Unix timestamp of the last market data event received; compare against \
+             scrape time to detect a silently stalled feed",
+        ))?;
+        let db_pool_connections = Gauge::with_opts(Opts::new(
+            "sol_volume_bot_db_pool_connections",
+            "Total Postgres connections currently held by the pool",
+        ))?;
+        let db_pool_idle_connections = Gauge::with_opts(Opts::new(
+            "sol_volume_bot_db_pool_idle_connections",
+            "Idle Postgres connections available in the pool",
+        ))?;
+
+        registry.register(Box::new(ticks_received_total.clone()))?;
+        registry.register(Box::new(orders_placed_total.clone()))?;
+        registry.register(Box::new(position_size.clone()))?;
+        registry.register(Box::new(unrealized_pnl.clone()))?;
+        registry.register(Box::new(signal_to_execution_latency.clone()))?;
+        registry.register(Box::new(market_data_last_tick_timestamp_seconds.clone()))?;
+        registry.register(Box::new(db_pool_connections.clone()))?;
+        registry.register(Box::new(db_pool_idle_connections.clone()))?;
+
+        Ok(Arc::new(Self {
+            registry,
+            ticks_received_total,
+            orders_placed_total,
+            position_size,
+            unrealized_pnl,
+            signal_to_execution_latency,
+            market_data_last_tick_timestamp_seconds,
+            db_pool_connections,
+            db_pool_idle_connections,
+        }))
+    }
+
+    /// Renders all registered metric families in the Prometheus text
+    /// exposition format for the `/metrics` endpoint.
+    pub fn render(&self) -> Result<String> {
+        let metric_families = self.registry.gather();
+        let mut buffer = Vec::new();
+        TextEncoder::new().encode(&metric_families, &mut buffer)?;
+        Ok(String::from_utf8(buffer)?)
+    }
+}
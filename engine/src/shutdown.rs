@@ -0,0 +1,42 @@
+use anyhow::Result;
+use tokio::signal::unix::{signal, SignalKind};
+use tokio::sync::broadcast;
+use tracing::info;
+
+/// Reason a shutdown was initiated, broadcast to every subsystem's `run()`
+/// loop so it can flush state and cancel in-flight work before returning,
+/// instead of being aborted mid-operation.
+#[derive(Debug, Clone, Copy)]
+pub enum ShutdownReason {
+    /// SIGINT (Ctrl-C)
+    Interrupt,
+    /// SIGTERM, e.g. from a container orchestrator stopping the process
+    Terminate,
+    /// SIGHUP; shuts down like the other signals, configuration is not reloaded
+    Reload,
+}
+
+/// Installs handlers for SIGINT, SIGTERM and SIGHUP and returns the sending
+/// half of a broadcast channel that fires once when any of them arrives.
+/// Each component subscribes its own receiver and races it against its work
+/// in a `select!` loop.
+pub fn install() -> Result<broadcast::Sender<ShutdownReason>> {
+    let (tx, _) = broadcast::channel(1);
+    let sender = tx.clone();
+
+    let mut sigterm = signal(SignalKind::terminate())?;
+    let mut sighup = signal(SignalKind::hangup())?;
+
+    tokio::spawn(async move {
+        let reason = tokio::select! {
+            _ = tokio::signal::ctrl_c() => ShutdownReason::Interrupt,
+            _ = sigterm.recv() => ShutdownReason::Terminate,
+            _ = sighup.recv() => ShutdownReason::Reload,
+        };
+
+        info!(?reason, "received shutdown signal");
+        let _ = sender.send(reason);
+    });
+
+    Ok(tx)
+}
@@ -1,21 +1,280 @@
-use crate::config::Config;
+use std::collections::VecDeque;
+use std::sync::Arc;
+
+use crate::config::{Config, ThresholdsConfig};
+use crate::data::MarketEvent;
+use crate::metrics::Metrics;
+use crate::shutdown::ShutdownReason;
 use anyhow::Result;
+use tokio::sync::broadcast;
+use tracing::{debug, info};
+
+/// Wilder's smoothed RSI period. Fixed here; not read from the configuration.
+const RSI_PERIOD: usize = 14;
+/// Bounds how far back the rolling session high is tracked (one day of
+/// 1-minute closes), so indicator memory stays flat regardless of uptime.
+const ROLLING_WINDOW: usize = 1_440;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Side {
+    Long,
+    Short,
+}
+
+impl Side {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Side::Long => "long",
+            Side::Short => "short",
+        }
+    }
+
+    pub fn parse(s: &str) -> Option<Self> {
+        match s {
+            "long" => Some(Side::Long),
+            "short" => Some(Side::Short),
+            _ => None,
+        }
+    }
+}
+
+/// A trade signal emitted to the `Executor`. `strength` is in `[0.0, 1.0]`
+/// and reflects how strongly the indicators agree, for sizing or filtering
+/// downstream.
+#[derive(Debug, Clone, Copy)]
+pub struct Signal {
+    pub side: Side,
+    pub strength: f64,
+}
+
+/// Wilder's smoothed RSI, seeded with a simple mean over the first
+/// `period` changes and smoothed thereafter.
+struct RsiState {
+    period: usize,
+    prev_close: Option<f64>,
+    seed_gains: Vec<f64>,
+    seed_losses: Vec<f64>,
+    avg_gain: f64,
+    avg_loss: f64,
+    warm: bool,
+}
+
+impl RsiState {
+    fn new(period: usize) -> Self {
+        Self {
+            period,
+            prev_close: None,
+            seed_gains: Vec::with_capacity(period),
+            seed_losses: Vec::with_capacity(period),
+            avg_gain: 0.0,
+            avg_loss: 0.0,
+            warm: false,
+        }
+    }
+
+    /// Feeds in the next close price, returning the current RSI once enough
+    /// history has accumulated to seed the averages.
+    fn update(&mut self, close: f64) -> Option<f64> {
+        let prev = self.prev_close.replace(close)?;
+        let change = close - prev;
+        let gain = change.max(0.0);
+        let loss = (-change).max(0.0);
+
+        if !self.warm {
+            self.seed_gains.push(gain);
+            self.seed_losses.push(loss);
+            if self.seed_gains.len() < self.period {
+                return None;
+            }
+            let n = self.period as f64;
+            self.avg_gain = self.seed_gains.iter().sum::<f64>() / n;
+            self.avg_loss = self.seed_losses.iter().sum::<f64>() / n;
+            self.warm = true;
+        } else {
+            let n = self.period as f64;
+            self.avg_gain = (self.avg_gain * (n - 1.0) + gain) / n;
+            self.avg_loss = (self.avg_loss * (n - 1.0) + loss) / n;
+        }
+
+        Some(self.rsi())
+    }
+
+    fn rsi(&self) -> f64 {
+        if self.avg_loss == 0.0 {
+            return 100.0;
+        }
+        let rs = self.avg_gain / self.avg_loss;
+        100.0 - 100.0 / (1.0 + rs)
+    }
+}
+
+/// Per-symbol indicator state: Wilder's RSI plus a bounded ring buffer of
+/// closes used to track the rolling session high.
+struct IndicatorState {
+    rsi: RsiState,
+    rolling_closes: VecDeque<f64>,
+}
+
+impl IndicatorState {
+    fn new() -> Self {
+        Self {
+            rsi: RsiState::new(RSI_PERIOD),
+            rolling_closes: VecDeque::with_capacity(ROLLING_WINDOW),
+        }
+    }
+
+    fn push_close(&mut self, close: f64) -> Option<f64> {
+        if self.rolling_closes.len() == ROLLING_WINDOW {
+            self.rolling_closes.pop_front();
+        }
+        self.rolling_closes.push_back(close);
+        self.rsi.update(close)
+    }
+
+    fn rolling_high(&self) -> f64 {
+        self.rolling_closes.iter().copied().fold(f64::MIN, f64::max)
+    }
+}
 
 pub struct SignalEngine {
-    _config: Config,
+    config: Config,
+    _metrics: Arc<Metrics>,
+    market_data: broadcast::Receiver<MarketEvent>,
+    tx: broadcast::Sender<Signal>,
 }
 
 impl SignalEngine {
-    pub async fn new(config: &Config) -> Result<Self> {
+    pub async fn new(
+        config: &Config,
+        metrics: Arc<Metrics>,
+        market_data: broadcast::Receiver<MarketEvent>,
+    ) -> Result<Self> {
+        let (tx, _) = broadcast::channel(64);
         Ok(Self {
-            _config: config.clone(),
+            config: config.clone(),
+            _metrics: metrics,
+            market_data,
+            tx,
         })
     }
 
-    pub async fn run(self) -> Result<()> {
-        // TODO: Implement signal engine
-        // This is a stub implementation for now
-        tokio::time::sleep(tokio::time::Duration::from_secs(3600)).await;
-        Ok(())
+    /// Subscribes to the signal stream consumed by the `Executor`.
+    pub fn subscribe(&self) -> broadcast::Receiver<Signal> {
+        self.tx.subscribe()
+    }
+
+    pub async fn run(mut self, mut shutdown: broadcast::Receiver<ShutdownReason>) -> Result<()> {
+        let mut state = IndicatorState::new();
+        let thresholds = self.config.thresholds.clone();
+
+        loop {
+            tokio::select! {
+                event = self.market_data.recv() => {
+                    match event {
+                        Ok(event) => self.on_market_event(&mut state, &thresholds, event),
+                        Err(broadcast::error::RecvError::Lagged(skipped)) => {
+                            debug!(skipped, "SignalEngine lagged behind market data feed");
+                        }
+                        Err(broadcast::error::RecvError::Closed) => {
+                            info!("market data channel closed, SignalEngine exiting");
+                            return Ok(());
+                        }
+                    }
+                }
+                reason = shutdown.recv() => {
+                    info!(?reason, "SignalEngine shutting down");
+                    return Ok(());
+                }
+            }
+        }
+    }
+
+    fn on_market_event(
+        &self,
+        state: &mut IndicatorState,
+        thresholds: &ThresholdsConfig,
+        event: MarketEvent,
+    ) {
+        if event.symbol() != self.config.symbols.spot {
+            return;
+        }
+
+        let MarketEvent::Kline { close, is_closed, .. } = event else {
+            return;
+        };
+        if !is_closed {
+            return;
+        }
+
+        let Some(rsi) = state.push_close(close) else {
+            return;
+        };
+        let rolling_high = state.rolling_high();
+        let drop_from_high = (rolling_high - close) / rolling_high;
+
+        let in_support_band = close >= thresholds.support_low && close <= thresholds.support_high;
+        let oversold = rsi <= thresholds.rsi_max;
+        let capitulation = drop_from_high > thresholds.meme_drop_pct;
+
+        if oversold && in_support_band && capitulation {
+            let strength = ((thresholds.rsi_max - rsi) / thresholds.rsi_max).clamp(0.0, 1.0);
+            let signal = Signal { side: Side::Long, strength };
+            debug!(rsi, close, drop_from_high, strength, "emitting long entry signal");
+            // No subscribers yet is not an error for the signal engine itself.
+            let _ = self.tx.send(signal);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rsi_warms_up_after_period_changes() {
+        let mut rsi = RsiState::new(3);
+        assert_eq!(rsi.update(100.0), None);
+        assert_eq!(rsi.update(101.0), None);
+        assert_eq!(rsi.update(102.0), None);
+        assert!(rsi.update(103.0).is_some());
+    }
+
+    #[test]
+    fn rsi_is_100_when_there_are_no_losses() {
+        let mut rsi = RsiState::new(3);
+        rsi.update(100.0);
+        rsi.update(101.0);
+        rsi.update(102.0);
+        let value = rsi.update(103.0).unwrap();
+        assert_eq!(value, 100.0);
+    }
+
+    #[test]
+    fn rsi_is_0_when_there_are_no_gains() {
+        let mut rsi = RsiState::new(3);
+        rsi.update(103.0);
+        rsi.update(102.0);
+        rsi.update(101.0);
+        let value = rsi.update(100.0).unwrap();
+        assert_eq!(value, 0.0);
+    }
+
+    #[test]
+    fn rsi_smooths_with_wilder_formula_after_seeding() {
+        // Seed over a flat 1-up/1-down alternation so avg_gain == avg_loss == 0.5
+        // after warmup, giving a known RSI of 50.0, then verify the next value
+        // moves according to Wilder's (n-1)/n smoothing rather than a plain mean.
+        let mut rsi = RsiState::new(2);
+        rsi.update(100.0); // prev = 100
+        rsi.update(101.0); // +1, seed_gains=[1], seed_losses=[0]
+        let seeded = rsi.update(100.0).unwrap(); // -1, seeds complete: avg_gain=0.5, avg_loss=0.5
+        assert_eq!(seeded, 50.0);
+
+        let next = rsi.update(102.0).unwrap(); // +2
+        let expected_avg_gain = (0.5 * 1.0 + 2.0) / 2.0;
+        let expected_avg_loss = (0.5 * 1.0 + 0.0) / 2.0;
+        let expected_rs = expected_avg_gain / expected_avg_loss;
+        let expected_rsi = 100.0 - 100.0 / (1.0 + expected_rs);
+        assert!((next - expected_rsi).abs() < 1e-9);
     }
 }
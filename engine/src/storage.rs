@@ -0,0 +1,196 @@
+use std::sync::Arc;
+
+use crate::config::Config;
+use crate::metrics::Metrics;
+use anyhow::Result;
+use async_trait::async_trait;
+use bb8::Pool;
+use bb8_postgres::PostgresConnectionManager;
+use tokio_postgres::NoTls;
+
+pub type PgPool = Pool<PostgresConnectionManager<NoTls>>;
+
+/// An order submitted to the exchange, persisted for audit and crash
+/// recovery.
+#[derive(Debug, Clone)]
+pub struct OrderRecord {
+    pub symbol: String,
+    pub side: String,
+    pub kind: OrderKind,
+    pub qty: f64,
+    pub price: f64,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub enum OrderKind {
+    Entry,
+    Stop,
+}
+
+impl OrderKind {
+    fn as_str(self) -> &'static str {
+        match self {
+            OrderKind::Entry => "entry",
+            OrderKind::Stop => "stop",
+        }
+    }
+}
+
+/// A fill reported by the exchange, persisted alongside the order it closes
+/// out or partially fills.
+#[derive(Debug, Clone)]
+pub struct FillRecord {
+    pub symbol: String,
+    pub qty: f64,
+    pub price: f64,
+}
+
+/// A periodic snapshot of account equity and unrealized PnL.
+#[derive(Debug, Clone)]
+pub struct PnlSnapshot {
+    pub equity: f64,
+    pub unrealized_pnl: f64,
+}
+
+/// The last known open position, reloaded on boot so the bot can resume
+/// reconciliation after a crash instead of starting blind.
+#[derive(Debug, Clone)]
+pub struct OpenPosition {
+    pub symbol: String,
+    pub side: String,
+    pub qty: f64,
+    pub entry_price: f64,
+    pub stop_price: f64,
+}
+
+/// Persists trade and fill history so a restart doesn't lose it. Implemented
+/// against Postgres via `PostgresStore`; the trait exists so `Executor` and
+/// `AccountManager` depend on storage semantics rather than a concrete
+/// database client.
+#[async_trait]
+pub trait Store: Send + Sync {
+    async fn save_order(&self, order: &OrderRecord) -> Result<()>;
+    async fn save_fill(&self, fill: &FillRecord) -> Result<()>;
+    async fn save_pnl_snapshot(&self, snapshot: &PnlSnapshot) -> Result<()>;
+    /// Records a newly opened position so it can be reloaded via
+    /// `load_open_position` if the bot crashes before it closes.
+    async fn save_position(&self, position: &OpenPosition) -> Result<()>;
+    /// Marks `symbol`'s open position as closed once the bot has flattened
+    /// or exited it.
+    async fn close_position(&self, symbol: &str) -> Result<()>;
+    async fn load_open_position(&self) -> Result<Option<OpenPosition>>;
+}
+
+/// `Store` backed by a bb8-pooled Postgres connection.
+pub struct PostgresStore {
+    pool: PgPool,
+    metrics: Arc<Metrics>,
+}
+
+impl PostgresStore {
+    pub async fn connect(config: &Config, metrics: Arc<Metrics>) -> Result<Self> {
+        let manager =
+            PostgresConnectionManager::new_from_stringlike(config.storage.database_url.clone(), NoTls)?;
+        let pool = Pool::builder()
+            .max_size(config.storage.pool_size)
+            .build(manager)
+            .await?;
+        let store = Self { pool, metrics };
+        store.observe_pool_health();
+        Ok(store)
+    }
+
+    /// Publishes pool utilization so connection exhaustion shows up in
+    /// Prometheus instead of as an unexplained latency spike.
+    fn observe_pool_health(&self) {
+        let state = self.pool.state();
+        self.metrics.db_pool_connections.set(state.connections as f64);
+        self.metrics.db_pool_idle_connections.set(state.idle_connections as f64);
+    }
+}
+
+#[async_trait]
+impl Store for PostgresStore {
+    async fn save_order(&self, order: &OrderRecord) -> Result<()> {
+        let kind = order.kind.as_str();
+        let conn = self.pool.get().await?;
+        conn.execute(
+            "INSERT INTO orders (symbol, side, kind, qty, price) VALUES ($1, $2, $3, $4, $5)",
+            &[&order.symbol, &order.side, &kind, &order.qty, &order.price],
+        )
+        .await?;
+        self.observe_pool_health();
+        Ok(())
+    }
+
+    async fn save_fill(&self, fill: &FillRecord) -> Result<()> {
+        let conn = self.pool.get().await?;
+        conn.execute(
+            "INSERT INTO fills (symbol, qty, price) VALUES ($1, $2, $3)",
+            &[&fill.symbol, &fill.qty, &fill.price],
+        )
+        .await?;
+        self.observe_pool_health();
+        Ok(())
+    }
+
+    async fn save_pnl_snapshot(&self, snapshot: &PnlSnapshot) -> Result<()> {
+        let conn = self.pool.get().await?;
+        conn.execute(
+            "INSERT INTO pnl_snapshots (equity, unrealized_pnl) VALUES ($1, $2)",
+            &[&snapshot.equity, &snapshot.unrealized_pnl],
+        )
+        .await?;
+        self.observe_pool_health();
+        Ok(())
+    }
+
+    async fn save_position(&self, position: &OpenPosition) -> Result<()> {
+        let conn = self.pool.get().await?;
+        conn.execute(
+            "INSERT INTO positions (symbol, side, qty, entry_price, stop_price) \
+             VALUES ($1, $2, $3, $4, $5)",
+            &[
+                &position.symbol,
+                &position.side,
+                &position.qty,
+                &position.entry_price,
+                &position.stop_price,
+            ],
+        )
+        .await?;
+        self.observe_pool_health();
+        Ok(())
+    }
+
+    async fn close_position(&self, symbol: &str) -> Result<()> {
+        let conn = self.pool.get().await?;
+        conn.execute(
+            "UPDATE positions SET closed_at = now() WHERE symbol = $1 AND closed_at IS NULL",
+            &[&symbol],
+        )
+        .await?;
+        self.observe_pool_health();
+        Ok(())
+    }
+
+    async fn load_open_position(&self) -> Result<Option<OpenPosition>> {
+        let conn = self.pool.get().await?;
+        let row = conn
+            .query_opt(
+                "SELECT symbol, side, qty, entry_price, stop_price FROM positions \
+                 WHERE closed_at IS NULL ORDER BY opened_at DESC LIMIT 1",
+                &[],
+            )
+            .await?;
+        self.observe_pool_health();
+
+        Ok(row.map(|row| OpenPosition {
+            symbol: row.get(0),
+            side: row.get(1),
+            qty: row.get(2),
+            entry_price: row.get(3),
+            stop_price: row.get(4),
+        }))
+    }
+}
@@ -1,23 +1,29 @@
+use std::sync::Arc;
+
 use crate::config::Config;
+use crate::metrics::Metrics;
 use anyhow::Result;
+use axum::extract::State;
 use axum::{routing::get, Router};
 
 pub struct TelemetryServer {
     _config: Config,
+    metrics: Arc<Metrics>,
 }
 
 impl TelemetryServer {
-    pub async fn new(config: &Config) -> Result<Self> {
+    pub async fn new(config: &Config, metrics: Arc<Metrics>) -> Result<Self> {
         Ok(Self {
             _config: config.clone(),
+            metrics,
         })
     }
 
     pub async fn run(self) -> Result<()> {
-        // TODO: Implement telemetry server with Prometheus metrics
         let app = Router::new()
             .route("/metrics", get(metrics_handler))
-            .route("/health", get(health_handler));
+            .route("/health", get(health_handler))
+            .with_state(self.metrics);
 
         let listener = tokio::net::TcpListener::bind("0.0.0.0:8080").await?;
         axum::serve(listener, app).await?;
@@ -25,9 +31,8 @@ impl TelemetryServer {
     }
 }
 
-async fn metrics_handler() -> &'static str {
-    // TODO: Return Prometheus metrics
-    "# HELP sol_volume_bot_status Bot status\n# TYPE sol_volume_bot_status gauge\nsol_volume_bot_status 1\n"
+async fn metrics_handler(State(metrics): State<Arc<Metrics>>) -> String {
+    metrics.render().unwrap_or_else(|e| format!("# failed to render metrics: {e}\n"))
 }
 
 async fn health_handler() -> &'static str {